@@ -0,0 +1,48 @@
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use std::fmt::Debug;
+use std::hash::Hash as StdHash;
+
+/// A cryptographic hash function usable as the backbone of a `MerkleTree`.
+///
+/// `hashv` takes the pieces to hash as separate slices rather than a single
+/// concatenated buffer, so callers (e.g. the domain-separation prefixes in
+/// `Node`) don't need to allocate just to glue a tag onto their data.
+pub trait Hasher {
+    type Hash: AsRef<[u8]> + Clone + Debug + PartialEq + Eq + StdHash;
+
+    fn hashv(data: &[&[u8]]) -> Self::Hash;
+}
+
+/// SHA-256, the default hasher and the one this crate has always used.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sha256Hasher;
+
+impl Hasher for Sha256Hasher {
+    type Hash = Vec<u8>;
+
+    fn hashv(data: &[&[u8]]) -> Self::Hash {
+        let mut hasher = Sha256::new();
+        for piece in data {
+            hasher.update(piece);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
+/// Keccak-256, for trees that need to interoperate with Ethereum and other
+/// EVM-based chains expecting proofs hashed this way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Keccak256Hasher;
+
+impl Hasher for Keccak256Hasher {
+    type Hash = Vec<u8>;
+
+    fn hashv(data: &[&[u8]]) -> Self::Hash {
+        let mut hasher = Keccak256::new();
+        for piece in data {
+            hasher.update(piece);
+        }
+        hasher.finalize().to_vec()
+    }
+}
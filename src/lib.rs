@@ -0,0 +1,13 @@
+//! A small Merkle tree toolkit: a generic `Hasher` trait, domain-separated
+//! leaf/internal/null hashing, fixed-depth sparse proofs, multi-leaf
+//! multiproofs, a content-addressed persistent/versioned tree, and a
+//! commutative sorted-pair hashing mode.
+
+pub mod hasher;
+pub mod multiproof;
+pub mod node;
+pub mod sorted;
+pub mod sparse;
+pub mod store;
+pub mod tree;
+pub mod versioned;
@@ -0,0 +1,192 @@
+use crate::hasher::Hasher;
+use crate::node::{hash_internal_pair, Node};
+use crate::tree::{MerkleTree, MerkleTreeError};
+use std::collections::{HashMap, HashSet};
+
+/// A pruned copy of the tree's shape: requested leaves keep their original
+/// data, everywhere else collapses to the single sibling hash needed to
+/// recombine up to the root. Because the pruning is a deterministic function
+/// of the tree and the requested leaf set, two callers proving the same
+/// leaves always produce an identical `MultiProofNode`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultiProofNode<H: Hasher> {
+    Leaf(Vec<u8>),
+    Sibling(H::Hash),
+    Internal(Box<MultiProofNode<H>>, Box<MultiProofNode<H>>),
+}
+
+/// A membership proof for several leaves at once, sharing whatever internal
+/// nodes their paths to the root have in common instead of repeating the
+/// overlapping sibling hashes a per-leaf `generate_proof` call would.
+#[derive(Clone, Debug)]
+pub struct MultiProof<H: Hasher> {
+    pub root: MultiProofNode<H>,
+}
+
+impl<H: Hasher> MerkleTree<H> {
+    pub fn generate_multiproof(&self, leaves: &[&[u8]]) -> Result<MultiProof<H>, MerkleTreeError> {
+        let requested: HashMap<H::Hash, Vec<u8>> = leaves
+            .iter()
+            .map(|data| (Node::<H>::hash_leaf(data), data.to_vec()))
+            .collect();
+
+        let mut found = 0usize;
+        let root = prune(self.root_node(), &requested, &mut found);
+
+        if found != requested.len() {
+            return Err(MerkleTreeError::ProofGenerationFailed);
+        }
+
+        Ok(MultiProof { root })
+    }
+}
+
+fn prune<H: Hasher>(
+    node: &Node<H>,
+    requested: &HashMap<H::Hash, Vec<u8>>,
+    found: &mut usize,
+) -> MultiProofNode<H> {
+    match node {
+        Node::Leaf { hash } => match requested.get(hash) {
+            Some(data) => {
+                *found += 1;
+                MultiProofNode::Leaf(data.clone())
+            }
+            None => MultiProofNode::Sibling(hash.clone()),
+        },
+        Node::Internal { hash, left, right } => {
+            let pruned_left = prune(left, requested, found);
+            let pruned_right = prune(right, requested, found);
+
+            // Neither side leads to a requested leaf: collapse the whole
+            // subtree into the one hash needed to reconstruct past it,
+            // rather than keeping two redundant sibling entries.
+            match (&pruned_left, &pruned_right) {
+                (MultiProofNode::Sibling(_), MultiProofNode::Sibling(_)) => {
+                    MultiProofNode::Sibling(hash.clone())
+                }
+                _ => MultiProofNode::Internal(Box::new(pruned_left), Box::new(pruned_right)),
+            }
+        }
+    }
+}
+
+/// Verify that `proof` covers every hash in `leaves` and that it
+/// reconstructs to `root`. It's not enough for `proof` to be internally
+/// consistent (any valid `MultiProof` reconstructs to *some* root) - a
+/// verifier needs to confirm the specific leaves it asked about are the
+/// ones embedded in it.
+pub fn verify_multiproof<H: Hasher>(leaves: &[&[u8]], proof: &MultiProof<H>, root: &H::Hash) -> bool {
+    if leaves.is_empty() {
+        return false;
+    }
+
+    let expected: HashSet<H::Hash> = leaves.iter().map(|data| Node::<H>::hash_leaf(data)).collect();
+    let mut covered = HashSet::new();
+
+    let computed_root = match reconstruct(&proof.root, &mut covered) {
+        Some(hash) => hash,
+        None => return false,
+    };
+
+    expected.iter().all(|hash| covered.contains(hash)) && &computed_root == root
+}
+
+fn reconstruct<H: Hasher>(node: &MultiProofNode<H>, covered: &mut HashSet<H::Hash>) -> Option<H::Hash> {
+    match node {
+        MultiProofNode::Leaf(data) => {
+            let hash = Node::<H>::hash_leaf(data);
+            covered.insert(hash.clone());
+            Some(hash)
+        }
+        MultiProofNode::Sibling(hash) => Some(hash.clone()),
+        MultiProofNode::Internal(left, right) => {
+            let left_hash = reconstruct(left, covered)?;
+            let right_hash = reconstruct(right, covered)?;
+            Some(hash_internal_pair::<H>(&left_hash, &right_hash))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::Sha256Hasher;
+
+    fn sample_tree() -> MerkleTree {
+        let transactions = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Charlie -> Dave, amount: 20".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+        MerkleTree::from(transactions)
+    }
+
+    #[test]
+    fn test_generate_and_verify_multiproof() {
+        let tree = sample_tree();
+        let leaves = [
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+        ];
+
+        let proof = tree.generate_multiproof(&leaves).expect("multiproof generation failed");
+        assert!(verify_multiproof::<Sha256Hasher>(&leaves, &proof, tree.root_hash()));
+    }
+
+    #[test]
+    fn test_multiproof_rejects_tampered_leaf() {
+        let tree = sample_tree();
+        let leaves = [b"tx: Alice -> Bob, amount: 10".as_ref()];
+        let mut proof = tree.generate_multiproof(&leaves).expect("multiproof generation failed");
+
+        // `leaves` has only Alice, so pruning collapses two levels deep:
+        // Internal(Internal(Leaf(alice), Sibling(charlie)), Sibling(eve_grace)).
+        if let MultiProofNode::Internal(left, _) = &mut proof.root {
+            if let MultiProofNode::Internal(left, _) = left.as_mut() {
+                if let MultiProofNode::Leaf(data) = left.as_mut() {
+                    data.push(0xff);
+                }
+            }
+        }
+
+        assert!(!verify_multiproof::<Sha256Hasher>(&leaves, &proof, tree.root_hash()));
+    }
+
+    #[test]
+    fn test_multiproof_rejects_leaves_it_does_not_cover() {
+        let tree = sample_tree();
+        let requested = [b"tx: Alice -> Bob, amount: 10".as_ref()];
+        let proof = tree.generate_multiproof(&requested).expect("multiproof generation failed");
+
+        // The proof is internally consistent and reconstructs to the real
+        // root, but it was never asked to cover this leaf.
+        let claimed = [b"tx: Eve -> Frank, amount: 30".as_ref()];
+        assert!(!verify_multiproof::<Sha256Hasher>(&claimed, &proof, tree.root_hash()));
+    }
+
+    #[test]
+    fn test_multiproof_errors_on_unknown_leaf() {
+        let tree = sample_tree();
+        let leaves = [b"tx: not in the tree".as_ref()];
+        assert!(tree.generate_multiproof(&leaves).is_err());
+    }
+
+    #[test]
+    fn test_multiproof_is_canonical() {
+        let tree = sample_tree();
+        let forward = [
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+        let reversed = [
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+        ];
+
+        let proof_a = tree.generate_multiproof(&forward).unwrap();
+        let proof_b = tree.generate_multiproof(&reversed).unwrap();
+        assert_eq!(proof_a.root, proof_b.root);
+    }
+}
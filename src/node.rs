@@ -0,0 +1,105 @@
+use crate::hasher::Hasher;
+
+/// Domain-separation tags prefixed onto hash inputs so a leaf hash can never
+/// be replayed as an internal node hash (or vice versa), closing the classic
+/// Merkle second-preimage attack.
+const LEAF_PREFIX: u8 = 0x00;
+const INTERNAL_PREFIX: u8 = 0x01;
+const NULL_PREFIX: u8 = 0x02;
+
+pub enum Node<H: Hasher> {
+    Leaf { hash: H::Hash },
+    Internal {
+        hash: H::Hash,
+        left: Box<Node<H>>,
+        right: Box<Node<H>>,
+    },
+}
+
+// Derived `Clone`/`Debug` would bound on `H: Clone`/`H: Debug`, but `H` is
+// never stored here - only `H::Hash` is. `Hasher` doesn't require either, so
+// a derive would make every `Node<H>` unusable for any `H` that doesn't
+// happen to implement them.
+impl<H: Hasher> Clone for Node<H> {
+    fn clone(&self) -> Self {
+        match self {
+            Node::Leaf { hash } => Node::Leaf { hash: hash.clone() },
+            Node::Internal { hash, left, right } => Node::Internal {
+                hash: hash.clone(),
+                left: left.clone(),
+                right: right.clone(),
+            },
+        }
+    }
+}
+
+impl<H: Hasher> std::fmt::Debug for Node<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Leaf { hash } => f.debug_struct("Leaf").field("hash", hash).finish(),
+            Node::Internal { hash, left, right } => f
+                .debug_struct("Internal")
+                .field("hash", hash)
+                .field("left", left)
+                .field("right", right)
+                .finish(),
+        }
+    }
+}
+
+impl<H: Hasher> Default for Node<H> {
+    fn default() -> Self {
+        // A distinct null-node hash, rather than all-zero padding, so an
+        // attacker can't pass off a padding node as a real leaf.
+        Node::Leaf { hash: null_hash::<H>() }
+    }
+}
+
+pub(crate) fn null_hash<H: Hasher>() -> H::Hash {
+    H::hashv(&[&[NULL_PREFIX]])
+}
+
+impl<H: Hasher> Node<H> {
+    pub(crate) fn hash_leaf(data: &[u8]) -> H::Hash {
+        H::hashv(&[&[LEAF_PREFIX], data])
+    }
+
+    pub(crate) fn new_leaf(data: &[u8]) -> Node<H> {
+        Node::Leaf {
+            hash: Self::hash_leaf(data),
+        }
+    }
+
+    pub(crate) fn new_internal(left: Box<Node<H>>, right: Box<Node<H>>) -> Node<H> {
+        let hash = H::hashv(&[&[INTERNAL_PREFIX], left.get_hash().as_ref(), right.get_hash().as_ref()]);
+        Node::Internal { hash, left, right }
+    }
+
+    /// Like `new_internal`, but hashes the two children in sorted order so
+    /// the combination step doesn't depend on which side is which.
+    pub(crate) fn new_internal_sorted(left: Box<Node<H>>, right: Box<Node<H>>) -> Node<H> {
+        let hash = hash_internal_sorted_pair::<H>(left.get_hash(), right.get_hash());
+        Node::Internal { hash, left, right }
+    }
+
+    pub(crate) fn get_hash(&self) -> &H::Hash {
+        match self {
+            Node::Leaf { hash } => hash,
+            Node::Internal { hash, .. } => hash,
+        }
+    }
+}
+
+pub(crate) fn hash_internal_pair<H: Hasher>(left: &H::Hash, right: &H::Hash) -> H::Hash {
+    H::hashv(&[&[INTERNAL_PREFIX], left.as_ref(), right.as_ref()])
+}
+
+/// Combine two hashes order-independently, so a verifier doesn't need to
+/// know which one was the left or right child.
+pub(crate) fn hash_internal_sorted_pair<H: Hasher>(a: &H::Hash, b: &H::Hash) -> H::Hash {
+    if a.as_ref() <= b.as_ref() {
+        hash_internal_pair::<H>(a, b)
+    } else {
+        hash_internal_pair::<H>(b, a)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::hasher::{Hasher, Sha256Hasher};
+use crate::node::{hash_internal_sorted_pair, Node};
+use crate::tree::MerkleTreeError;
+
+/// A Merkle tree that hashes each internal node's children in sorted order,
+/// `hash(0x01 || min(left, right) || max(left, right))`, so a membership
+/// proof doesn't need a per-step flag for which side the sibling is on.
+/// The pair ordering follows OpenZeppelin and Pyth; the leaf (`0x00`) and
+/// internal (`0x01`) domain-separation prefixes are the same as in
+/// `MerkleTree`. This is a distinct mode from `MerkleTree`: callers
+/// that need to distinguish left/right (e.g. index-ordered trees) should
+/// keep using the positional proofs there instead.
+pub struct SortedMerkleTree<H: Hasher = Sha256Hasher> {
+    root: Node<H>,
+}
+
+impl<H: Hasher> From<Vec<&[u8]>> for SortedMerkleTree<H> {
+    fn from(data_blocks: Vec<&[u8]>) -> Self {
+        let mut nodes: Vec<Box<Node<H>>> = data_blocks
+            .into_iter()
+            .map(|data| Box::new(Node::new_leaf(data)))
+            .collect();
+        let root = Self::build_tree(&mut nodes);
+        SortedMerkleTree { root: *root }
+    }
+}
+
+impl<H: Hasher> SortedMerkleTree<H> {
+    fn build_tree(nodes: &mut Vec<Box<Node<H>>>) -> Box<Node<H>> {
+        while nodes.len() > 1 {
+            let mut new_level = Vec::with_capacity(nodes.len().div_ceil(2));
+
+            for chunk in nodes.chunks(2) {
+                let left = chunk[0].clone();
+                let right = chunk.get(1).cloned().unwrap_or_else(|| Box::new(Node::default()));
+                new_level.push(Box::new(Node::new_internal_sorted(left, right)));
+            }
+
+            *nodes = new_level;
+        }
+
+        nodes.pop().expect("tree must have at least one node")
+    }
+
+    pub fn root_hash(&self) -> &H::Hash {
+        self.root.get_hash()
+    }
+
+    pub fn generate_proof(&self, data: &[u8]) -> Result<Vec<H::Hash>, MerkleTreeError> {
+        let mut proof = Vec::new();
+        let target = Node::<H>::hash_leaf(data);
+
+        if Self::generate_proof_recursive(&self.root, &target, &mut proof) {
+            Ok(proof)
+        } else {
+            Err(MerkleTreeError::ProofGenerationFailed)
+        }
+    }
+
+    fn generate_proof_recursive(node: &Node<H>, target: &H::Hash, proof: &mut Vec<H::Hash>) -> bool {
+        match node {
+            Node::Leaf { hash } => hash == target,
+            Node::Internal { left, right, .. } => {
+                if Self::generate_proof_recursive(left, target, proof) {
+                    proof.push(right.get_hash().clone());
+                    true
+                } else if Self::generate_proof_recursive(right, target, proof) {
+                    proof.push(left.get_hash().clone());
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    pub fn verify(&self, data: &[u8], proof: &[H::Hash]) -> bool {
+        Self::verify_sorted(data, proof, self.root_hash())
+    }
+
+    pub fn verify_sorted(data: &[u8], proof: &[H::Hash], root: &H::Hash) -> bool {
+        let mut current = Node::<H>::hash_leaf(data);
+
+        for sibling in proof {
+            current = hash_internal_sorted_pair::<H>(&current, sibling);
+        }
+
+        &current == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::Sha256Hasher;
+
+    #[test]
+    fn test_generate_and_verify_sorted_proof() {
+        let transactions = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let tree: SortedMerkleTree = SortedMerkleTree::from(transactions);
+        let root = tree.root_hash();
+
+        let data = b"tx: Eve -> Frank, amount: 30";
+        let proof = tree.generate_proof(data).expect("proof generation failed");
+        assert!(SortedMerkleTree::<Sha256Hasher>::verify_sorted(data, &proof, root));
+
+        let wrong_data = b"tx: Eve -> Frank, amount: 31";
+        assert!(!SortedMerkleTree::<Sha256Hasher>::verify_sorted(wrong_data, &proof, root));
+    }
+
+    #[test]
+    fn test_sorted_root_matches_regardless_of_construction_order() {
+        // Sorting the pair before hashing means swapping a pair of leaves
+        // that land on the same two tree positions produces the same root.
+        let a = b"leaf-a".as_ref();
+        let b = b"leaf-b".as_ref();
+
+        let tree_ab: SortedMerkleTree = SortedMerkleTree::from(vec![a, b]);
+        let tree_ba: SortedMerkleTree = SortedMerkleTree::from(vec![b, a]);
+
+        assert_eq!(tree_ab.root_hash(), tree_ba.root_hash());
+    }
+}
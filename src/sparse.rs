@@ -0,0 +1,84 @@
+use crate::hasher::Hasher;
+use crate::node::{hash_internal_pair, Node};
+
+/// Precomputed hashes for empty subtrees at each depth of a fixed-depth
+/// sparse tree: `zero_hashes[0]` is the hash of an empty leaf, and
+/// `zero_hashes[k]` is the hash of two `zero_hashes[k - 1]` subtrees combined.
+pub fn zero_hashes<H: Hasher>(depth: usize) -> Vec<H::Hash> {
+    let mut hashes = Vec::with_capacity(depth + 1);
+    hashes.push(Node::<H>::hash_leaf(&[]));
+    for k in 1..=depth {
+        let prev = hashes[k - 1].clone();
+        hashes.push(hash_internal_pair::<H>(&prev, &prev));
+    }
+    hashes
+}
+
+/// Verify a membership proof for `leaf` at `index` in a fixed-depth
+/// (`depth`) sparse Merkle tree against `root`, in the style of
+/// Lighthouse's `merkle_proof`.
+///
+/// `branch` supplies one sibling hash per level (`branch.len() == depth`).
+/// Unlike positional proofs there is no explicit left/right flag per step:
+/// bit `k` of `index` says whether the running hash is the left (`0`) or
+/// right (`1`) child at level `k`.
+pub fn verify_merkle_proof<H: Hasher>(
+    leaf: &[u8],
+    branch: &[H::Hash],
+    depth: usize,
+    index: usize,
+    root: &H::Hash,
+) -> bool {
+    if branch.len() != depth {
+        return false;
+    }
+
+    let mut current = Node::<H>::hash_leaf(leaf);
+    for (k, sibling) in branch.iter().enumerate() {
+        current = if (index >> k) & 1 == 0 {
+            hash_internal_pair::<H>(&current, sibling)
+        } else {
+            hash_internal_pair::<H>(sibling, &current)
+        };
+    }
+
+    &current == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::Sha256Hasher;
+
+    #[test]
+    fn test_zero_hashes_are_deterministic_and_distinct() {
+        let hashes = zero_hashes::<Sha256Hasher>(4);
+        assert_eq!(hashes, zero_hashes::<Sha256Hasher>(4));
+        assert_eq!(hashes.len(), 5);
+        for pair in hashes.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_verify_merkle_proof_against_all_zero_leaves() {
+        let depth = 3;
+        let zeros = zero_hashes::<Sha256Hasher>(depth);
+        let branch: Vec<_> = zeros[..depth].to_vec();
+        let root = &zeros[depth];
+
+        for index in 0..(1usize << depth) {
+            assert!(verify_merkle_proof::<Sha256Hasher>(&[], &branch, depth, index, root));
+        }
+    }
+
+    #[test]
+    fn test_verify_merkle_proof_rejects_wrong_leaf() {
+        let depth = 3;
+        let zeros = zero_hashes::<Sha256Hasher>(depth);
+        let branch: Vec<_> = zeros[..depth].to_vec();
+        let root = &zeros[depth];
+
+        assert!(!verify_merkle_proof::<Sha256Hasher>(b"not empty", &branch, depth, 0, root));
+    }
+}
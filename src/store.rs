@@ -0,0 +1,92 @@
+use crate::hasher::Hasher;
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+/// A node as persisted by a `NodeStore`: content-addressed by its own hash,
+/// so the store never needs to record a node's hash separately from the key
+/// it's stored under.
+pub enum StoredNode<H: Hasher> {
+    Leaf,
+    Internal { left: H::Hash, right: H::Hash },
+}
+
+// See the equivalent impls on `Node` in `node.rs`: deriving here would bound
+// on `H` itself rather than `H::Hash`, which is the only thing stored.
+impl<H: Hasher> Clone for StoredNode<H> {
+    fn clone(&self) -> Self {
+        match self {
+            StoredNode::Leaf => StoredNode::Leaf,
+            StoredNode::Internal { left, right } => StoredNode::Internal {
+                left: left.clone(),
+                right: right.clone(),
+            },
+        }
+    }
+}
+
+impl<H: Hasher> std::fmt::Debug for StoredNode<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoredNode::Leaf => f.debug_struct("Leaf").finish(),
+            StoredNode::Internal { left, right } => {
+                f.debug_struct("Internal").field("left", left).field("right", right).finish()
+            }
+        }
+    }
+}
+
+impl<H: Hasher> PartialEq for StoredNode<H> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StoredNode::Leaf, StoredNode::Leaf) => true,
+            (StoredNode::Internal { left: l1, right: r1 }, StoredNode::Internal { left: l2, right: r2 }) => {
+                l1 == l2 && r1 == r2
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<H: Hasher> Eq for StoredNode<H> {}
+
+/// An error from a `NodeStore` backend, e.g. disk I/O failure or lock
+/// contention in a persistent store.
+#[derive(Error, Debug)]
+#[error("node store error: {0}")]
+pub struct StoreError(pub String);
+
+/// Pluggable storage for a `PersistentMerkleTree`'s content-addressed nodes.
+///
+/// Because nodes are keyed by hash, a subtree that's unchanged between two
+/// versions of the tree is written once and shared by every root that
+/// references it.
+pub trait NodeStore<H: Hasher> {
+    fn get(&self, hash: &H::Hash) -> Result<Option<StoredNode<H>>, StoreError>;
+    fn put(&mut self, hash: H::Hash, node: StoredNode<H>) -> Result<(), StoreError>;
+}
+
+/// The default `NodeStore`, backed by an in-memory `HashMap`.
+#[derive(Debug)]
+pub struct InMemoryStore<H: Hasher> {
+    nodes: HashMap<H::Hash, StoredNode<H>>,
+}
+
+impl<H: Hasher> Default for InMemoryStore<H> {
+    fn default() -> Self {
+        InMemoryStore { nodes: HashMap::new() }
+    }
+}
+
+impl<H: Hasher> NodeStore<H> for InMemoryStore<H> {
+    fn get(&self, hash: &H::Hash) -> Result<Option<StoredNode<H>>, StoreError> {
+        Ok(self.nodes.get(hash).cloned())
+    }
+
+    fn put(&mut self, hash: H::Hash, node: StoredNode<H>) -> Result<(), StoreError> {
+        self.nodes.entry(hash).or_insert(node);
+        Ok(())
+    }
+}
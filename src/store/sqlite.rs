@@ -0,0 +1,69 @@
+use super::{NodeStore, StoreError, StoredNode};
+use crate::hasher::Hasher;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::marker::PhantomData;
+
+/// A `NodeStore` backed by a SQLite table, for trees too large to keep
+/// entirely in memory or that need to persist across process restarts.
+///
+/// `left`/`right` are `NULL` for leaf rows; their presence is what
+/// distinguishes a `StoredNode::Leaf` from a `StoredNode::Internal`.
+pub struct SqliteStore<H: Hasher> {
+    conn: Connection,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> SqliteStore<H> {
+    pub fn open(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS merkle_nodes (
+                hash  BLOB PRIMARY KEY,
+                left  BLOB,
+                right BLOB
+            )",
+            [],
+        )?;
+        Ok(SqliteStore { conn, _hasher: PhantomData })
+    }
+}
+
+impl<H: Hasher> NodeStore<H> for SqliteStore<H>
+where
+    H::Hash: From<Vec<u8>>,
+{
+    fn get(&self, hash: &H::Hash) -> Result<Option<StoredNode<H>>, StoreError> {
+        self.conn
+            .query_row(
+                "SELECT left, right FROM merkle_nodes WHERE hash = ?1",
+                params![hash.as_ref()],
+                |row| {
+                    let left: Option<Vec<u8>> = row.get(0)?;
+                    let right: Option<Vec<u8>> = row.get(1)?;
+                    Ok(match (left, right) {
+                        (Some(left), Some(right)) => StoredNode::Internal {
+                            left: left.into(),
+                            right: right.into(),
+                        },
+                        _ => StoredNode::Leaf,
+                    })
+                },
+            )
+            .optional()
+            .map_err(|e| StoreError(e.to_string()))
+    }
+
+    fn put(&mut self, hash: H::Hash, node: StoredNode<H>) -> Result<(), StoreError> {
+        let (left, right) = match &node {
+            StoredNode::Leaf => (None, None),
+            StoredNode::Internal { left, right } => (Some(left.as_ref()), Some(right.as_ref())),
+        };
+
+        self.conn
+            .execute(
+                "INSERT OR IGNORE INTO merkle_nodes (hash, left, right) VALUES (?1, ?2, ?3)",
+                params![hash.as_ref(), left, right],
+            )
+            .map(|_| ())
+            .map_err(|e| StoreError(e.to_string()))
+    }
+}
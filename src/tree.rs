@@ -0,0 +1,192 @@
+use crate::hasher::{Hasher, Sha256Hasher};
+use crate::node::{hash_internal_pair, Node};
+use crate::store::StoreError;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct MerkleTree<H: Hasher = Sha256Hasher> {
+    root: Node<H>,
+}
+
+#[derive(Error, Debug)]
+pub enum MerkleTreeError {
+    #[error("Failed to generate proof")]
+    ProofGenerationFailed,
+    #[error("Leaf index {index} is out of bounds for a tree with {len} leaves")]
+    LeafIndexOutOfBounds { index: usize, len: usize },
+    #[error(transparent)]
+    Store(#[from] StoreError),
+}
+
+impl<H: Hasher> From<Vec<&[u8]>> for MerkleTree<H> {
+    fn from(data_blocks: Vec<&[u8]>) -> Self {
+        let mut nodes: Vec<Box<Node<H>>> = data_blocks
+            .into_iter()
+            .map(|data| Box::new(Node::new_leaf(data)))
+            .collect();
+        let root = MerkleTree::build_tree(&mut nodes);
+        MerkleTree { root: *root }
+    }
+}
+
+impl<H: Hasher> MerkleTree<H> {
+    fn build_tree(nodes: &mut Vec<Box<Node<H>>>) -> Box<Node<H>> {
+        while nodes.len() > 1 {
+            let mut new_level = Vec::with_capacity(nodes.len().div_ceil(2));
+
+            for chunk in nodes.chunks(2) {
+                let left = chunk[0].clone();
+                let right = chunk.get(1).cloned().unwrap_or_else(|| Box::new(Node::default()));
+                new_level.push(Box::new(Node::new_internal(left, right)));
+            }
+
+            *nodes = new_level;
+        }
+
+        nodes.pop().expect("Tree must have at least one node")
+    }
+
+    pub fn root_hash(&self) -> &H::Hash {
+        self.root.get_hash()
+    }
+
+    pub(crate) fn root_node(&self) -> &Node<H> {
+        &self.root
+    }
+
+    pub fn generate_proof(&self, data: &[u8]) -> Result<Vec<(H::Hash, bool)>, MerkleTreeError> {
+        let mut proof = Vec::new();
+        let data_hash = Node::<H>::hash_leaf(data);
+
+        if !self.generate_proof_recursive(&self.root, &data_hash, &mut proof) {
+            return Err(MerkleTreeError::ProofGenerationFailed);
+        }
+
+        Ok(proof)
+    }
+
+    fn generate_proof_recursive(
+        &self,
+        node: &Node<H>,
+        target_hash: &H::Hash,
+        proof: &mut Vec<(H::Hash, bool)>,
+    ) -> bool {
+        match node {
+            Node::Leaf { hash } => hash == target_hash,
+            Node::Internal { left, right, .. } => {
+                if self.generate_proof_recursive(left, target_hash, proof) {
+                    proof.push((right.get_hash().clone(), false));
+                    true
+                } else if self.generate_proof_recursive(right, target_hash, proof) {
+                    proof.push((left.get_hash().clone(), true));
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    pub fn verify(&self, data: &[u8], proof: &[(H::Hash, bool)]) -> bool {
+        Self::verify_proof(data, proof, self.root_hash())
+    }
+
+    pub fn verify_proof(data: &[u8], proof: &[(H::Hash, bool)], root_hash: &H::Hash) -> bool {
+        let mut current_hash = Node::<H>::hash_leaf(data);
+
+        for (sibling_hash, is_left) in proof {
+            current_hash = if *is_left {
+                hash_internal_pair::<H>(sibling_hash, &current_hash)
+            } else {
+                hash_internal_pair::<H>(&current_hash, sibling_hash)
+            };
+        }
+
+        &current_hash == root_hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::Keccak256Hasher;
+
+    #[test]
+    fn test_merkle_tree_construction() {
+        let transactions = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let merkle_tree: MerkleTree = MerkleTree::from(transactions.clone());
+
+        let root_hash = merkle_tree.root_hash();
+        assert!(!root_hash.is_empty());
+
+        let expected_root_hash = merkle_tree.root_hash();
+        assert_eq!(root_hash, expected_root_hash);
+    }
+
+    #[test]
+    fn test_merkle_tree_root_hash_changes_with_data() {
+        let transactions_1 = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Charlie -> Dave, amount: 20".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let transactions_2 = vec![
+            b"tx: Alice -> Bob, amount: 15".as_ref(), // Only this transaction is different
+            b"tx: Charlie -> Dave, amount: 20".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let merkle_tree_1: MerkleTree = MerkleTree::from(transactions_1);
+        let merkle_tree_2: MerkleTree = MerkleTree::from(transactions_2);
+
+        assert_ne!(merkle_tree_1.root_hash(), merkle_tree_2.root_hash());
+    }
+
+    #[test]
+    fn test_generate_and_verify_proof() {
+        let transactions = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let merkle_tree: MerkleTree = MerkleTree::from(transactions);
+        let root_hash = merkle_tree.root_hash();
+
+        let data = b"tx: Alice -> Bob, amount: 10";
+        if let Ok(proof) = merkle_tree.generate_proof(data) {
+            let is_valid = MerkleTree::<Sha256Hasher>::verify_proof(data, &proof, root_hash);
+            assert!(is_valid, "Proof should be valid");
+
+            // Test with incorrect data
+            let incorrect_data = b"tx: Alice -> Bob, amount: 20";
+            let is_invalid = MerkleTree::<Sha256Hasher>::verify_proof(incorrect_data, &proof, root_hash);
+            assert!(!is_invalid, "Proof should be invalid for incorrect data");
+        } else {
+            panic!("Proof generation failed");
+        }
+    }
+
+    #[test]
+    fn test_keccak256_hasher() {
+        let transactions = vec![
+            b"tx: Alice -> Bob, amount: 10".as_ref(),
+            b"tx: Eve -> Frank, amount: 30".as_ref(),
+            b"tx: Grace -> Heidi, amount: 40".as_ref(),
+        ];
+
+        let merkle_tree: MerkleTree<Keccak256Hasher> = MerkleTree::from(transactions);
+        let root_hash = merkle_tree.root_hash();
+
+        let data = b"tx: Alice -> Bob, amount: 10";
+        let proof = merkle_tree.generate_proof(data).expect("proof generation failed");
+        assert!(MerkleTree::<Keccak256Hasher>::verify_proof(data, &proof, root_hash));
+    }
+}
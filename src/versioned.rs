@@ -0,0 +1,209 @@
+use crate::hasher::{Hasher, Sha256Hasher};
+use crate::node::{hash_internal_pair, null_hash, Node};
+use crate::store::{InMemoryStore, NodeStore, StoredNode};
+use crate::tree::MerkleTreeError;
+use std::collections::HashSet;
+
+/// An incremental, versioned Merkle tree.
+///
+/// Unlike `MerkleTree`, which is build-once and fully in-memory, this
+/// supports appending (`insert_leaf`) and replacing (`update_leaf`) leaves
+/// over time while keeping every previously committed root queryable via
+/// `generate_proof_at`. Nodes are stored content-addressed by hash in a
+/// pluggable `NodeStore`, so a subtree unchanged between two versions is
+/// written once and shared by both roots rather than copied.
+pub struct PersistentMerkleTree<H: Hasher = Sha256Hasher, S: NodeStore<H> = InMemoryStore<H>> {
+    store: S,
+    leaves: Vec<H::Hash>,
+    roots: Vec<H::Hash>,
+    root_index: HashSet<H::Hash>,
+}
+
+impl<H: Hasher, S: NodeStore<H> + Default> Default for PersistentMerkleTree<H, S> {
+    fn default() -> Self {
+        PersistentMerkleTree::with_store(S::default())
+    }
+}
+
+impl<H: Hasher, S: NodeStore<H>> PersistentMerkleTree<H, S> {
+    pub fn with_store(store: S) -> Self {
+        PersistentMerkleTree {
+            store,
+            leaves: Vec::new(),
+            roots: Vec::new(),
+            root_index: HashSet::new(),
+        }
+    }
+
+    /// The most recently committed root, or `None` if no leaf has been
+    /// inserted yet.
+    pub fn root_hash(&self) -> Option<&H::Hash> {
+        self.roots.last()
+    }
+
+    /// Every root committed so far, oldest first.
+    pub fn roots(&self) -> &[H::Hash] {
+        &self.roots
+    }
+
+    pub fn insert_leaf(&mut self, data: &[u8]) -> Result<H::Hash, MerkleTreeError> {
+        let leaf_hash = Node::<H>::hash_leaf(data);
+        self.store.put(leaf_hash.clone(), StoredNode::Leaf)?;
+        self.leaves.push(leaf_hash);
+        self.commit()
+    }
+
+    pub fn update_leaf(&mut self, index: usize, data: &[u8]) -> Result<H::Hash, MerkleTreeError> {
+        if index >= self.leaves.len() {
+            return Err(MerkleTreeError::LeafIndexOutOfBounds {
+                index,
+                len: self.leaves.len(),
+            });
+        }
+
+        let leaf_hash = Node::<H>::hash_leaf(data);
+        self.store.put(leaf_hash.clone(), StoredNode::Leaf)?;
+        self.leaves[index] = leaf_hash;
+        self.commit()
+    }
+
+    fn commit(&mut self) -> Result<H::Hash, MerkleTreeError> {
+        let mut level = self.leaves.clone();
+
+        while level.len() > 1 {
+            let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+
+            for chunk in level.chunks(2) {
+                let left = chunk[0].clone();
+                let right = chunk.get(1).cloned().unwrap_or_else(null_hash::<H>);
+                let parent = hash_internal_pair::<H>(&left, &right);
+                self.store.put(parent.clone(), StoredNode::Internal { left, right })?;
+                next_level.push(parent);
+            }
+
+            level = next_level;
+        }
+
+        let root = level.into_iter().next().expect("tree has at least one leaf after insert");
+        self.roots.push(root.clone());
+        self.root_index.insert(root.clone());
+        Ok(root)
+    }
+
+    /// Generate a membership proof for `data` against a historical `root`
+    /// (any value previously returned by `insert_leaf`/`update_leaf`), not
+    /// just the current one.
+    ///
+    /// The returned proof has the same shape as `MerkleTree::generate_proof`
+    /// and can be checked with `MerkleTree::<H>::verify_proof`.
+    pub fn generate_proof_at(
+        &self,
+        root: &H::Hash,
+        data: &[u8],
+    ) -> Result<Vec<(H::Hash, bool)>, MerkleTreeError> {
+        if !self.root_index.contains(root) {
+            return Err(MerkleTreeError::ProofGenerationFailed);
+        }
+
+        let target = Node::<H>::hash_leaf(data);
+        let mut proof = Vec::new();
+
+        if self.search(root, &target, &mut proof)? {
+            Ok(proof)
+        } else {
+            Err(MerkleTreeError::ProofGenerationFailed)
+        }
+    }
+
+    fn search(
+        &self,
+        node_hash: &H::Hash,
+        target: &H::Hash,
+        proof: &mut Vec<(H::Hash, bool)>,
+    ) -> Result<bool, MerkleTreeError> {
+        if node_hash == target {
+            return Ok(true);
+        }
+
+        match self.store.get(node_hash)? {
+            Some(StoredNode::Leaf) | None => Ok(false),
+            Some(StoredNode::Internal { left, right }) => {
+                if self.search(&left, target, proof)? {
+                    proof.push((right, false));
+                    Ok(true)
+                } else if self.search(&right, target, proof)? {
+                    proof.push((left, true));
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hasher::Sha256Hasher;
+    use crate::tree::MerkleTree;
+
+    #[test]
+    fn test_historical_roots_stay_queryable() {
+        let mut tree: PersistentMerkleTree<Sha256Hasher> = PersistentMerkleTree::default();
+
+        let root_after_first = tree.insert_leaf(b"tx: Alice -> Bob, amount: 10").expect("insert failed");
+        let root_after_second = tree.insert_leaf(b"tx: Eve -> Frank, amount: 30").expect("insert failed");
+
+        assert_ne!(root_after_first, root_after_second);
+        assert_eq!(tree.roots(), &[root_after_first.clone(), root_after_second.clone()]);
+
+        let proof = tree
+            .generate_proof_at(&root_after_first, b"tx: Alice -> Bob, amount: 10")
+            .expect("proof against historical root should succeed");
+        assert!(MerkleTree::<Sha256Hasher>::verify_proof(
+            b"tx: Alice -> Bob, amount: 10",
+            &proof,
+            &root_after_first,
+        ));
+    }
+
+    #[test]
+    fn test_update_leaf_changes_root_but_keeps_history() {
+        let mut tree: PersistentMerkleTree<Sha256Hasher> = PersistentMerkleTree::default();
+        tree.insert_leaf(b"tx: Alice -> Bob, amount: 10").expect("insert failed");
+        let root_before = tree.insert_leaf(b"tx: Eve -> Frank, amount: 30").expect("insert failed");
+
+        let root_after = tree
+            .update_leaf(1, b"tx: Eve -> Frank, amount: 99")
+            .expect("index 1 is in bounds");
+
+        assert_ne!(root_before, root_after);
+
+        let proof = tree
+            .generate_proof_at(&root_before, b"tx: Eve -> Frank, amount: 30")
+            .expect("old leaf value should still be provable against the old root");
+        assert!(MerkleTree::<Sha256Hasher>::verify_proof(
+            b"tx: Eve -> Frank, amount: 30",
+            &proof,
+            &root_before,
+        ));
+    }
+
+    #[test]
+    fn test_update_leaf_rejects_out_of_bounds_index() {
+        let mut tree: PersistentMerkleTree<Sha256Hasher> = PersistentMerkleTree::default();
+        tree.insert_leaf(b"tx: Alice -> Bob, amount: 10").expect("insert failed");
+
+        assert!(tree.update_leaf(1, b"tx: Eve -> Frank, amount: 30").is_err());
+    }
+
+    #[test]
+    fn test_generate_proof_at_rejects_unknown_root() {
+        let mut tree: PersistentMerkleTree<Sha256Hasher> = PersistentMerkleTree::default();
+        tree.insert_leaf(b"tx: Alice -> Bob, amount: 10").expect("insert failed");
+
+        let bogus_root = Node::<Sha256Hasher>::hash_leaf(b"not a real root");
+        assert!(tree.generate_proof_at(&bogus_root, b"tx: Alice -> Bob, amount: 10").is_err());
+    }
+}